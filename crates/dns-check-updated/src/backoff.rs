@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+/// Exponential backoff schedule for DNS propagation polling.
+///
+/// Delays start at `initial_delay`, grow by `multiplier` each attempt up to
+/// `max_delay`, and polling stops once `deadline` has elapsed since the
+/// first attempt — whichever of delay or deadline the caller hits first.
+#[derive(Debug, Clone, Copy)]
+pub struct Backoff {
+    pub initial_delay: Duration,
+    pub multiplier: f64,
+    pub max_delay: Duration,
+    pub deadline: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(60),
+            deadline: Duration::from_secs(3600),
+        }
+    }
+}
+
+impl Backoff {
+    /// An endless iterator of the delay to wait before each next attempt.
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let mut delay = self.initial_delay;
+        let multiplier = self.multiplier;
+        let max_delay = self.max_delay;
+        std::iter::from_fn(move || {
+            let current = delay;
+            // Clamp in f64 seconds before converting back to a Duration:
+            // `delay.mul_f64(multiplier)` panics on overflow for a large
+            // enough multiplier, even when the clamped result would fit.
+            let next_secs = (delay.as_secs_f64() * multiplier).min(max_delay.as_secs_f64());
+            delay = Duration::try_from_secs_f64(next_secs).unwrap_or(max_delay);
+            Some(current)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Backoff;
+    use std::time::Duration;
+
+    #[test]
+    fn delays_grow_and_cap() {
+        let backoff = Backoff {
+            initial_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(5),
+            deadline: Duration::from_secs(60),
+        };
+        let first_five: Vec<_> = backoff.delays().take(5).collect();
+        assert_eq!(
+            first_five,
+            vec![
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(4),
+                Duration::from_secs(5),
+                Duration::from_secs(5),
+            ]
+        );
+    }
+
+    #[test]
+    fn huge_multiplier_does_not_panic() {
+        let backoff = Backoff {
+            initial_delay: Duration::from_secs(1),
+            multiplier: 1e300,
+            max_delay: Duration::from_secs(60),
+            deadline: Duration::from_secs(3600),
+        };
+        let first_three: Vec<_> = backoff.delays().take(3).collect();
+        assert_eq!(
+            first_three,
+            vec![
+                Duration::from_secs(1),
+                Duration::from_secs(60),
+                Duration::from_secs(60),
+            ]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::Backoff;
+use arc_swap::ArcSwap;
+use std::{
+    net::IpAddr,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub backoff: Backoff,
+    pub nameservers: Vec<IpAddr>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            backoff: Backoff::default(),
+            nameservers: vec![
+                IpAddr::V4(std::net::Ipv4Addr::new(8, 8, 8, 8)),
+                IpAddr::V4(std::net::Ipv4Addr::new(8, 8, 4, 4)),
+            ],
+        }
+    }
+}
+
+fn swap() -> &'static ArcSwap<Config> {
+    static CONFIG: OnceLock<ArcSwap<Config>> = OnceLock::new();
+    CONFIG.get_or_init(|| ArcSwap::from_pointee(Config::default()))
+}
+
+pub fn current() -> Arc<Config> {
+    swap().load_full()
+}
+
+pub fn set(config: Config) {
+    swap().store(Arc::new(config));
+}
+
+// The file holds one `key = value` setting per line: `initial_delay_secs`,
+// `multiplier`, `max_delay_secs`, `deadline_secs` and `nameservers` (a
+// comma-separated list of IPs). Unknown or missing keys fall back to the
+// current value.
+pub fn reload<P: AsRef<std::path::Path>>(path: P) -> crate::Result<()> {
+    let text = std::fs::read_to_string(path)?;
+    let mut config = (*current()).clone();
+
+    for line in text.lines() {
+        let line = line.trim();
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        match key {
+            "initial_delay_secs" => {
+                if let Ok(secs) = value.parse() {
+                    config.backoff.initial_delay = Duration::from_secs(secs);
+                }
+            }
+            "multiplier" => {
+                if let Ok(multiplier) = value.parse::<f64>() {
+                    if multiplier.is_finite() && multiplier >= 0.0 {
+                        config.backoff.multiplier = multiplier;
+                    }
+                }
+            }
+            "max_delay_secs" => {
+                if let Ok(secs) = value.parse() {
+                    config.backoff.max_delay = Duration::from_secs(secs);
+                }
+            }
+            "deadline_secs" => {
+                if let Ok(secs) = value.parse() {
+                    config.backoff.deadline = Duration::from_secs(secs);
+                }
+            }
+            "nameservers" => {
+                let nameservers: Vec<IpAddr> =
+                    value.split(',').filter_map(|ip| ip.trim().parse().ok()).collect();
+                if !nameservers.is_empty() {
+                    config.nameservers = nameservers;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    set(config);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reload_rejects_invalid_multiplier_and_applies_nameservers() {
+        let mut path = std::env::temp_dir();
+        path.push("dns_check_updated_config_reload_test.txt");
+
+        std::fs::write(&path, "multiplier = -1\nnameservers = 1.1.1.1,1.0.0.1\n").unwrap();
+        reload(&path).unwrap();
+        let config = current();
+        assert_eq!(config.backoff.multiplier, Backoff::default().multiplier);
+        assert_eq!(
+            config.nameservers,
+            vec![
+                "1.1.1.1".parse::<IpAddr>().unwrap(),
+                "1.0.0.1".parse::<IpAddr>().unwrap(),
+            ]
+        );
+
+        std::fs::write(&path, "multiplier = nan\n").unwrap();
+        reload(&path).unwrap();
+        assert_eq!(current().backoff.multiplier, Backoff::default().multiplier);
+
+        std::fs::write(&path, "multiplier = 2.5\n").unwrap();
+        reload(&path).unwrap();
+        assert_eq!(current().backoff.multiplier, 2.5);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
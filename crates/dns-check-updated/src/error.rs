@@ -0,0 +1,33 @@
+use hickory_resolver::error::{ResolveError, ResolveErrorKind};
+use hickory_resolver::proto::op::ResponseCode;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("dns resolve error: {0}")]
+    Resolve(#[from] ResolveError),
+
+    #[error("timeout checking acme challenge record")]
+    AcmeChallege,
+
+    #[error("dnssec validation failed: answer is bogus")]
+    Bogus,
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl Error {
+    pub(crate) fn from_resolve(err: ResolveError) -> Self {
+        // A validating resolver answers SERVFAIL for a DNSSEC-bogus
+        // response (RFC 4035 section 5.5) instead of surfacing a
+        // dedicated DNSSEC error kind, so that's the structured signal
+        // to match on here.
+        match err.kind() {
+            ResolveErrorKind::NoRecordsFound {
+                response_code: ResponseCode::ServFail,
+                ..
+            } => Error::Bogus,
+            _ => Error::Resolve(err),
+        }
+    }
+}
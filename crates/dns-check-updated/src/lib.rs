@@ -2,19 +2,21 @@ use hickory_resolver::{
     config::{LookupIpStrategy, NameServerConfigGroup, ResolverConfig, ResolverOpts},
     Resolver,
 };
-use std::{convert::identity, net::IpAddr, thread::sleep, time::Duration};
+use std::{net::IpAddr, time::Instant};
 
 use crate::error::Error;
-pub use resolver::ResolverType;
+pub use backoff::Backoff;
+pub use config::{current as current_config, reload as reload_config, Config};
+pub use resolver::{AcmeChallenge, AuthoritativeResolvers, DnssecOptions, ResolverType};
+use resolver::{AcmeChallengeAsync, AuthoritativeResolversAsync};
 
+mod backoff;
+mod config;
 mod error;
 mod resolver;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-const MAX_RETRIES: usize = 720;
-const WAIT_SECONDS: u64 = 5;
-
 fn ipv6_resolver(
     group: NameServerConfigGroup,
     recursion: bool,
@@ -39,31 +41,186 @@ pub fn has_acme_challenge<S>(domain_name: S, challenge: S) -> Result<()>
 where
     S: AsRef<str>,
 {
-    let resolvers = ResolverType::Google
-        .recursive_resolver(true)
-        .and_then(|resolver| resolver.authoritive_resolvers(domain_name.as_ref()))?;
-
-    let mut i: usize = 0;
-
-    sleep(Duration::from_secs(1));
-    while !resolvers
-        .iter()
-        .map(|resolver| resolver.has_single_acme(domain_name.as_ref(), challenge.as_ref()))
-        .collect::<Result<Vec<_>>>()?
-        .into_iter()
-        .all(identity)
-        && i < MAX_RETRIES
-    {
-        i += 1;
-        tracing::warn!("Attempt {} failed", i);
-        sleep(Duration::from_secs(WAIT_SECONDS));
-    }
-    if i >= MAX_RETRIES {
-        tracing::error!("Timeout checking acme challenge record");
-        Err(Error::AcmeChallege)
-    } else {
-        Ok(())
+    block_on(has_acme_challenge_async(
+        domain_name,
+        challenge,
+        current_config().backoff,
+    ))
+}
+
+pub fn has_acme_challenges<S>(domain_name: S, challenges: &[String]) -> Result<()>
+where
+    S: AsRef<str>,
+{
+    block_on(has_acme_challenges_async(
+        domain_name,
+        challenges,
+        current_config().backoff,
+    ))
+}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new()
+        .expect("failed to start tokio runtime")
+        .block_on(future)
+}
+
+pub async fn has_acme_challenge_async<S>(
+    domain_name: S,
+    challenge: S,
+    backoff: Backoff,
+) -> Result<()>
+where
+    S: AsRef<str>,
+{
+    let resolver = ResolverType::Configured.recursive_resolver_async(true).await?;
+    let resolvers = resolver.authoritive_resolvers_async(domain_name.as_ref()).await?;
+
+    let deadline = Instant::now() + backoff.deadline;
+    for delay in backoff.delays() {
+        let mut satisfied = true;
+        for resolver in &resolvers {
+            if !resolver
+                .has_single_acme_async(domain_name.as_ref(), challenge.as_ref())
+                .await?
+            {
+                satisfied = false;
+                break;
+            }
+        }
+        if satisfied {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        tracing::warn!("Attempt failed, retrying in {:?}", delay);
+        tokio::time::sleep(delay).await;
     }
+
+    tracing::error!("Timeout checking acme challenge record");
+    Err(Error::AcmeChallege)
+}
+
+pub async fn has_acme_challenge_dnssec_async<S>(
+    domain_name: S,
+    challenge: S,
+    backoff: Backoff,
+    dnssec: DnssecOptions,
+) -> Result<()>
+where
+    S: AsRef<str>,
+{
+    let resolver = ResolverType::Configured
+        .recursive_resolver_dnssec_async(true, &dnssec)
+        .await?;
+    let resolvers = resolver
+        .authoritive_resolvers_dnssec_async(domain_name.as_ref(), &dnssec)
+        .await?;
+
+    let deadline = Instant::now() + backoff.deadline;
+    for delay in backoff.delays() {
+        let mut satisfied = true;
+        for resolver in &resolvers {
+            if !resolver
+                .has_single_acme_async(domain_name.as_ref(), challenge.as_ref())
+                .await?
+            {
+                satisfied = false;
+                break;
+            }
+        }
+        if satisfied {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        tracing::warn!("Attempt failed, retrying in {:?}", delay);
+        tokio::time::sleep(delay).await;
+    }
+
+    tracing::error!("Timeout checking acme challenge record");
+    Err(Error::AcmeChallege)
+}
+
+pub async fn has_acme_challenges_async<S>(
+    domain_name: S,
+    challenges: &[String],
+    backoff: Backoff,
+) -> Result<()>
+where
+    S: AsRef<str>,
+{
+    let resolver = ResolverType::Configured.recursive_resolver_async(true).await?;
+    let resolvers = resolver.authoritive_resolvers_async(domain_name.as_ref()).await?;
+
+    let deadline = Instant::now() + backoff.deadline;
+    for delay in backoff.delays() {
+        let mut satisfied = true;
+        for resolver in &resolvers {
+            if !resolver
+                .has_acme_challenges_async(domain_name.as_ref(), challenges)
+                .await?
+            {
+                satisfied = false;
+                break;
+            }
+        }
+        if satisfied {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        tracing::warn!("Attempt failed, retrying in {:?}", delay);
+        tokio::time::sleep(delay).await;
+    }
+
+    tracing::error!("Timeout checking acme challenge records");
+    Err(Error::AcmeChallege)
+}
+
+pub async fn has_acme_challenges_dnssec_async<S>(
+    domain_name: S,
+    challenges: &[String],
+    backoff: Backoff,
+    dnssec: DnssecOptions,
+) -> Result<()>
+where
+    S: AsRef<str>,
+{
+    let resolver = ResolverType::Configured
+        .recursive_resolver_dnssec_async(true, &dnssec)
+        .await?;
+    let resolvers = resolver
+        .authoritive_resolvers_dnssec_async(domain_name.as_ref(), &dnssec)
+        .await?;
+
+    let deadline = Instant::now() + backoff.deadline;
+    for delay in backoff.delays() {
+        let mut satisfied = true;
+        for resolver in &resolvers {
+            if !resolver
+                .has_acme_challenges_async(domain_name.as_ref(), challenges)
+                .await?
+            {
+                satisfied = false;
+                break;
+            }
+        }
+        if satisfied {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        tracing::warn!("Attempt failed, retrying in {:?}", delay);
+        tokio::time::sleep(delay).await;
+    }
+
+    tracing::error!("Timeout checking acme challenge records");
+    Err(Error::AcmeChallege)
 }
 
 #[cfg(test)]
@@ -83,7 +240,7 @@ mod tests {
     }
 
     fn aaaa_to_ipv6(aaaa: AAAA) -> IpAddr {
-        IpAddr::V6((*aaaa).clone())
+        IpAddr::V6(*aaaa)
     }
 
     fn lookup(name: &str) -> impl Fn(Resolver) -> Result<Ipv6Lookup, Error> + '_ {
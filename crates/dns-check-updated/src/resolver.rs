@@ -0,0 +1,254 @@
+use hickory_resolver::{
+    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
+    Resolver, TokioAsyncResolver,
+};
+use std::{collections::HashSet, net::IpAddr};
+
+use crate::{recursive_resolver, Result};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DnssecOptions {
+    // Validates against hickory's built-in root trust anchor.
+    pub validate: bool,
+}
+
+impl DnssecOptions {
+    pub fn validated() -> Self {
+        Self { validate: true }
+    }
+
+    fn apply(&self, options: &mut ResolverOpts) {
+        options.validate = self.validate;
+    }
+}
+
+const GOOGLE: [IpAddr; 2] = [
+    IpAddr::V4(std::net::Ipv4Addr::new(8, 8, 8, 8)),
+    IpAddr::V4(std::net::Ipv4Addr::new(8, 8, 4, 4)),
+];
+
+const CLOUDFLARE: [IpAddr; 2] = [
+    IpAddr::V4(std::net::Ipv4Addr::new(1, 1, 1, 1)),
+    IpAddr::V4(std::net::Ipv4Addr::new(1, 0, 0, 1)),
+];
+
+pub enum ResolverType {
+    Google,
+    Cloudflare,
+    // The nameservers from the hot-reloadable crate::config::Config, picked
+    // up fresh on every call instead of being compiled in.
+    Configured,
+}
+
+impl ResolverType {
+    fn ips(&self) -> Vec<IpAddr> {
+        match self {
+            ResolverType::Google => GOOGLE.to_vec(),
+            ResolverType::Cloudflare => CLOUDFLARE.to_vec(),
+            ResolverType::Configured => crate::config::current().nameservers.clone(),
+        }
+    }
+
+    pub fn resolver(&self, ipv6_only: bool) -> Result<Resolver> {
+        recursive_resolver(&self.ips(), ipv6_only)
+    }
+
+    pub fn recursive_resolver(&self, ipv6_only: bool) -> Result<Resolver> {
+        recursive_resolver(&self.ips(), ipv6_only)
+    }
+
+    pub fn recursive_resolver_dnssec(
+        &self,
+        ipv6_only: bool,
+        dnssec: &DnssecOptions,
+    ) -> Result<Resolver> {
+        recursive_resolver_with_dnssec(&self.ips(), ipv6_only, dnssec)
+    }
+
+    pub async fn recursive_resolver_async(&self, ipv6_only: bool) -> Result<TokioAsyncResolver> {
+        recursive_resolver_async_with_dnssec(&self.ips(), ipv6_only, &DnssecOptions::default()).await
+    }
+
+    pub async fn recursive_resolver_dnssec_async(
+        &self,
+        ipv6_only: bool,
+        dnssec: &DnssecOptions,
+    ) -> Result<TokioAsyncResolver> {
+        recursive_resolver_async_with_dnssec(&self.ips(), ipv6_only, dnssec).await
+    }
+}
+
+fn recursive_resolver_with_dnssec(
+    ips: &[IpAddr],
+    ipv6_only: bool,
+    dnssec: &DnssecOptions,
+) -> Result<Resolver> {
+    let group = NameServerConfigGroup::from_ips_clear(ips, 53, false);
+    let config = ResolverConfig::from_parts(None, vec![], group);
+    let mut options = ResolverOpts::default();
+    if ipv6_only {
+        options.ip_strategy = hickory_resolver::config::LookupIpStrategy::Ipv6Only;
+    }
+    options.recursion_desired = true;
+    options.use_hosts_file = false;
+    dnssec.apply(&mut options);
+    Resolver::new(config, options).map_err(crate::error::Error::from)
+}
+
+async fn recursive_resolver_async_with_dnssec(
+    ips: &[IpAddr],
+    ipv6_only: bool,
+    dnssec: &DnssecOptions,
+) -> Result<TokioAsyncResolver> {
+    let group = NameServerConfigGroup::from_ips_clear(ips, 53, false);
+    let config = ResolverConfig::from_parts(None, vec![], group);
+    let mut options = ResolverOpts::default();
+    if ipv6_only {
+        options.ip_strategy = hickory_resolver::config::LookupIpStrategy::Ipv6Only;
+    }
+    options.recursion_desired = true;
+    options.use_hosts_file = false;
+    dnssec.apply(&mut options);
+    Ok(TokioAsyncResolver::tokio(config, options))
+}
+
+pub trait AuthoritativeResolvers {
+    fn authoritive_resolvers(&self, domain_name: &str) -> Result<Vec<Resolver>>;
+
+    fn authoritive_resolvers_dnssec(
+        &self,
+        domain_name: &str,
+        dnssec: &DnssecOptions,
+    ) -> Result<Vec<Resolver>>;
+}
+
+impl AuthoritativeResolvers for Resolver {
+    fn authoritive_resolvers(&self, domain_name: &str) -> Result<Vec<Resolver>> {
+        self.authoritive_resolvers_dnssec(domain_name, &DnssecOptions::default())
+    }
+
+    fn authoritive_resolvers_dnssec(
+        &self,
+        domain_name: &str,
+        dnssec: &DnssecOptions,
+    ) -> Result<Vec<Resolver>> {
+        self.ns_lookup(domain_name)?
+            .into_iter()
+            .map(|ns| ns.to_string())
+            .map(|hostname| {
+                self.lookup_ip(hostname.as_str())?
+                    .iter()
+                    .next()
+                    .ok_or(crate::error::Error::AcmeChallege)
+                    .and_then(|ip| recursive_resolver_with_dnssec(&[ip], false, dnssec))
+            })
+            .collect()
+    }
+}
+
+pub trait AcmeChallenge {
+    fn has_single_acme(&self, domain_name: &str, challenge: &str) -> Result<bool>;
+
+    // Succeeds when every value in `challenges` is present among this
+    // resolver's `_acme-challenge.<domain_name>` TXT records, regardless of
+    // any extra, stale values also present (set-containment, not equality).
+    fn has_acme_challenges(&self, domain_name: &str, challenges: &[String]) -> Result<bool>;
+}
+
+impl AcmeChallenge for Resolver {
+    fn has_single_acme(&self, domain_name: &str, challenge: &str) -> Result<bool> {
+        Ok(acme_challenge_values(self, domain_name)?.contains(challenge))
+    }
+
+    fn has_acme_challenges(&self, domain_name: &str, challenges: &[String]) -> Result<bool> {
+        let found = acme_challenge_values(self, domain_name)?;
+        Ok(challenges.iter().all(|challenge| found.contains(challenge)))
+    }
+}
+
+fn acme_challenge_values(resolver: &Resolver, domain_name: &str) -> Result<HashSet<String>> {
+    let name = format!("_acme-challenge.{domain_name}");
+    Ok(resolver
+        .txt_lookup(name)
+        .map_err(crate::error::Error::from_resolve)?
+        .iter()
+        .map(|record| record.to_string().trim_matches('"').to_owned())
+        .collect())
+}
+
+pub trait AuthoritativeResolversAsync {
+    async fn authoritive_resolvers_async(&self, domain_name: &str) -> Result<Vec<TokioAsyncResolver>>;
+
+    async fn authoritive_resolvers_dnssec_async(
+        &self,
+        domain_name: &str,
+        dnssec: &DnssecOptions,
+    ) -> Result<Vec<TokioAsyncResolver>>;
+}
+
+impl AuthoritativeResolversAsync for TokioAsyncResolver {
+    async fn authoritive_resolvers_async(&self, domain_name: &str) -> Result<Vec<TokioAsyncResolver>> {
+        self.authoritive_resolvers_dnssec_async(domain_name, &DnssecOptions::default())
+            .await
+    }
+
+    async fn authoritive_resolvers_dnssec_async(
+        &self,
+        domain_name: &str,
+        dnssec: &DnssecOptions,
+    ) -> Result<Vec<TokioAsyncResolver>> {
+        let nameservers = self.ns_lookup(domain_name).await?;
+        let mut resolvers = Vec::new();
+        for ns in nameservers {
+            let hostname = ns.to_string();
+            let ip = self
+                .lookup_ip(hostname.as_str())
+                .await?
+                .iter()
+                .next()
+                .ok_or(crate::error::Error::AcmeChallege)?;
+            resolvers.push(recursive_resolver_async_with_dnssec(&[ip], false, dnssec).await?);
+        }
+        Ok(resolvers)
+    }
+}
+
+pub trait AcmeChallengeAsync {
+    async fn has_single_acme_async(&self, domain_name: &str, challenge: &str) -> Result<bool>;
+    async fn has_acme_challenges_async(
+        &self,
+        domain_name: &str,
+        challenges: &[String],
+    ) -> Result<bool>;
+}
+
+impl AcmeChallengeAsync for TokioAsyncResolver {
+    async fn has_single_acme_async(&self, domain_name: &str, challenge: &str) -> Result<bool> {
+        Ok(acme_challenge_values_async(self, domain_name)
+            .await?
+            .contains(challenge))
+    }
+
+    async fn has_acme_challenges_async(
+        &self,
+        domain_name: &str,
+        challenges: &[String],
+    ) -> Result<bool> {
+        let found = acme_challenge_values_async(self, domain_name).await?;
+        Ok(challenges.iter().all(|challenge| found.contains(challenge)))
+    }
+}
+
+async fn acme_challenge_values_async(
+    resolver: &TokioAsyncResolver,
+    domain_name: &str,
+) -> Result<HashSet<String>> {
+    let name = format!("_acme-challenge.{domain_name}");
+    Ok(resolver
+        .txt_lookup(name)
+        .await
+        .map_err(crate::error::Error::from_resolve)?
+        .iter()
+        .map(|record| record.to_string().trim_matches('"').to_owned())
+        .collect())
+}
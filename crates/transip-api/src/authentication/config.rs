@@ -0,0 +1,69 @@
+use arc_swap::ArcSwap;
+use std::{
+    path::PathBuf,
+    sync::{Arc, OnceLock},
+};
+
+use crate::Result;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub token_path: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            token_path: PathBuf::from("token.txt"),
+        }
+    }
+}
+
+fn swap() -> &'static ArcSwap<Config> {
+    static CONFIG: OnceLock<ArcSwap<Config>> = OnceLock::new();
+    CONFIG.get_or_init(|| ArcSwap::from_pointee(Config::default()))
+}
+
+pub fn current() -> Arc<Config> {
+    swap().load_full()
+}
+
+pub fn set(config: Config) {
+    swap().store(Arc::new(config));
+}
+
+// The file holds a single `token_path = <path>` line; unset or unreadable
+// keys leave the current value untouched.
+pub fn reload<P: AsRef<std::path::Path>>(path: P) -> Result<()> {
+    let text = std::fs::read_to_string(path)?;
+    let mut config = (*current()).clone();
+
+    for line in text.lines() {
+        let Some((key, value)) = line.trim().split_once('=') else {
+            continue;
+        };
+        if key.trim() == "token_path" {
+            config.token_path = PathBuf::from(value.trim());
+        }
+    }
+
+    set(config);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reload_updates_token_path() {
+        let mut path = std::env::temp_dir();
+        path.push("transip_api_authentication_config_reload_test.txt");
+        std::fs::write(&path, "token_path = /tmp/custom_token.txt\n").unwrap();
+
+        reload(&path).unwrap();
+
+        assert_eq!(current().token_path, PathBuf::from("/tmp/custom_token.txt"));
+        std::fs::remove_file(&path).ok();
+    }
+}
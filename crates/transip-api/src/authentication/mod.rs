@@ -0,0 +1,9 @@
+mod config;
+mod signature;
+mod token;
+mod token_request;
+
+pub use config::{current as current_config, reload as reload_config, Config};
+pub use signature::{PrivateKey, SignatureAlgorithm};
+pub use token::{Token, TokenExpired, TokenResponse};
+pub use token_request::{request_token, TokenRequest};
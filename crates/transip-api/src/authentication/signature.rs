@@ -0,0 +1,182 @@
+use crate::{Error, Result};
+use ed25519_dalek::{Signer, SigningKey as Ed25519SigningKey};
+use p256::ecdsa::{Signature as EcdsaSignature, SigningKey as EcdsaSigningKey};
+use pkcs8::DecodePrivateKey;
+use rsa::{
+    pkcs1v15::SigningKey as RsaSigningKey,
+    sha2::{Sha256, Sha512},
+    signature::SignatureEncoding,
+    RsaPrivateKey,
+};
+use std::{fs, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureAlgorithm {
+    /// RSASSA-PKCS1-v1_5 using SHA-256.
+    Rs256,
+    /// RSASSA-PKCS1-v1_5 using SHA-512.
+    Rs512,
+    /// ECDSA using the NIST P-256 curve, signature encoded as `r || s`.
+    Es256,
+    /// Ed25519.
+    EdDsa,
+}
+
+enum KeyMaterial {
+    Rsa(RsaPrivateKey),
+    Es256(EcdsaSigningKey),
+    Ed25519(Ed25519SigningKey),
+}
+
+pub struct PrivateKey(KeyMaterial);
+
+impl PrivateKey {
+    pub fn try_from_pem_file<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let pem = fs::read_to_string(path)?;
+        Self::try_from_pem(&pem)
+    }
+
+    fn try_from_pem(pem: &str) -> Result<Self> {
+        if let Ok(key) = RsaPrivateKey::from_pkcs8_pem(pem) {
+            return Ok(Self(KeyMaterial::Rsa(key)));
+        }
+        if let Ok(key) = EcdsaSigningKey::from_pkcs8_pem(pem) {
+            return Ok(Self(KeyMaterial::Es256(key)));
+        }
+        if let Ok(key) = Ed25519SigningKey::from_pkcs8_pem(pem) {
+            return Ok(Self(KeyMaterial::Ed25519(key)));
+        }
+        Err(Error::Key)
+    }
+
+    // RSA keys list Rs512 before Rs256 so `sign_with_default_algorithm` keeps
+    // signing with RSA-SHA512, matching the hash TransIP's auth endpoint has
+    // always been sent for these keys.
+    pub fn supported_algorithms(&self) -> &'static [SignatureAlgorithm] {
+        match self.0 {
+            KeyMaterial::Rsa(_) => &[SignatureAlgorithm::Rs512, SignatureAlgorithm::Rs256],
+            KeyMaterial::Es256(_) => &[SignatureAlgorithm::Es256],
+            KeyMaterial::Ed25519(_) => &[SignatureAlgorithm::EdDsa],
+        }
+    }
+
+    pub fn sign(&self, algorithm: SignatureAlgorithm, bytes: &[u8]) -> Result<String> {
+        if !self.supported_algorithms().contains(&algorithm) {
+            return Err(Error::UnsupportedAlgorithm);
+        }
+        let signature = match (&self.0, algorithm) {
+            (KeyMaterial::Rsa(key), SignatureAlgorithm::Rs256) => {
+                RsaSigningKey::<Sha256>::new(key.clone())
+                    .try_sign(bytes)
+                    .map(|signature| signature.to_vec())
+                    .map_err(|_| Error::Signature)?
+            }
+            (KeyMaterial::Rsa(key), SignatureAlgorithm::Rs512) => {
+                RsaSigningKey::<Sha512>::new(key.clone())
+                    .try_sign(bytes)
+                    .map(|signature| signature.to_vec())
+                    .map_err(|_| Error::Signature)?
+            }
+            (KeyMaterial::Es256(key), SignatureAlgorithm::Es256) => {
+                let signature: EcdsaSignature =
+                    key.try_sign(bytes).map_err(|_| Error::Signature)?;
+                signature.to_vec()
+            }
+            (KeyMaterial::Ed25519(key), SignatureAlgorithm::EdDsa) => {
+                key.try_sign(bytes).map_err(|_| Error::Signature)?.to_vec()
+            }
+            _ => return Err(Error::UnsupportedAlgorithm),
+        };
+        Ok(base64::encode(signature))
+    }
+
+    pub fn sign_with_default_algorithm(&self, bytes: &[u8]) -> Result<(SignatureAlgorithm, String)> {
+        let algorithm = self.supported_algorithms()[0];
+        self.sign(algorithm, bytes).map(|signature| (algorithm, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pkcs8::{EncodePrivateKey, LineEnding};
+
+    fn rsa_key() -> PrivateKey {
+        let key = RsaPrivateKey::new(&mut rand::thread_rng(), 2048).expect("generate rsa key");
+        PrivateKey(KeyMaterial::Rsa(key))
+    }
+
+    fn ecdsa_key() -> PrivateKey {
+        PrivateKey(KeyMaterial::Es256(EcdsaSigningKey::random(&mut rand::thread_rng())))
+    }
+
+    #[test]
+    fn rsa_default_algorithm_is_rs512() {
+        let key = rsa_key();
+        let (algorithm, _) = key.sign_with_default_algorithm(b"payload").unwrap();
+        assert_eq!(algorithm, SignatureAlgorithm::Rs512);
+    }
+
+    #[test]
+    fn rsa_key_rejects_es256() {
+        let key = rsa_key();
+        assert!(matches!(
+            key.sign(SignatureAlgorithm::Es256, b"payload"),
+            Err(Error::UnsupportedAlgorithm)
+        ));
+    }
+
+    #[test]
+    fn ecdsa_key_rejects_eddsa() {
+        let key = ecdsa_key();
+        assert!(matches!(
+            key.sign(SignatureAlgorithm::EdDsa, b"payload"),
+            Err(Error::UnsupportedAlgorithm)
+        ));
+    }
+
+    #[test]
+    fn rsa_key_round_trips_through_pem() {
+        let key = RsaPrivateKey::new(&mut rand::thread_rng(), 2048).expect("generate rsa key");
+        let pem = key.to_pkcs8_pem(LineEnding::LF).expect("encode pkcs8 pem");
+        let key = PrivateKey::try_from_pem(&pem).unwrap();
+        assert_eq!(
+            key.supported_algorithms(),
+            &[SignatureAlgorithm::Rs512, SignatureAlgorithm::Rs256]
+        );
+    }
+
+    #[test]
+    fn ecdsa_key_round_trips_through_pem() {
+        let key = EcdsaSigningKey::random(&mut rand::thread_rng());
+        let pem = key.to_pkcs8_pem(LineEnding::LF).expect("encode pkcs8 pem");
+        let key = PrivateKey::try_from_pem(&pem).unwrap();
+        assert_eq!(key.supported_algorithms(), &[SignatureAlgorithm::Es256]);
+    }
+
+    #[test]
+    fn ed25519_key_round_trips_through_pem() {
+        let key = Ed25519SigningKey::generate(&mut rand::thread_rng());
+        let pem = key.to_pkcs8_pem(LineEnding::LF).expect("encode pkcs8 pem");
+        let key = PrivateKey::try_from_pem(&pem).unwrap();
+        assert_eq!(key.supported_algorithms(), &[SignatureAlgorithm::EdDsa]);
+    }
+
+    #[test]
+    fn try_from_pem_file_round_trips_rsa_key() {
+        let key = RsaPrivateKey::new(&mut rand::thread_rng(), 2048).expect("generate rsa key");
+        let pem = key.to_pkcs8_pem(LineEnding::LF).expect("encode pkcs8 pem");
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("transip-api-test-key-{:?}.pem", std::thread::current().id()));
+        std::fs::write(&path, pem.as_bytes()).unwrap();
+        let key = PrivateKey::try_from_pem_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(
+            key.supported_algorithms(),
+            &[SignatureAlgorithm::Rs512, SignatureAlgorithm::Rs256]
+        );
+    }
+}
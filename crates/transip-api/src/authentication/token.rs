@@ -53,6 +53,7 @@ impl Token {
         OpenOptions::new()
             .create(true)
             .write(true)
+            .truncate(true)
             .open(path)
             .map_err(Into::into)
             .and_then(|file| self.try_to_write(file))
@@ -69,6 +70,10 @@ impl Token {
             .and_then(Token::try_from_reader)
     }
 
+    pub fn try_from_configured_path() -> Result<Self> {
+        Token::try_from_file(super::current_config().token_path.as_path())
+    }
+
     pub fn raw(&self) -> &str {
         self.raw.as_str()
     }
@@ -0,0 +1,87 @@
+use super::signature::PrivateKey;
+use crate::authentication::{Token, TokenResponse};
+use crate::{Error, Result};
+use rand::{distributions::Alphanumeric, Rng};
+use serde::Serialize;
+
+const AUTH_URL: &str = "https://api.transip.nl/v6/auth";
+const NONCE_LEN: usize = 16;
+
+#[derive(Serialize)]
+pub struct TokenRequest {
+    login: String,
+    nonce: String,
+    read_only: bool,
+    expiration_time: String,
+    label: String,
+    global_key: bool,
+}
+
+impl TokenRequest {
+    pub fn new<S, L>(login: S, label: L) -> Self
+    where
+        S: Into<String>,
+        L: Into<String>,
+    {
+        Self {
+            login: login.into(),
+            nonce: random_nonce(),
+            read_only: false,
+            expiration_time: "30 minutes".to_owned(),
+            label: label.into(),
+            global_key: false,
+        }
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn expiration_time<S: Into<String>>(mut self, expiration_time: S) -> Self {
+        self.expiration_time = expiration_time.into();
+        self
+    }
+
+    pub fn global_key(mut self, global_key: bool) -> Self {
+        self.global_key = global_key;
+        self
+    }
+
+    // Signed and sent unchanged; re-serializing risks a body/signature mismatch.
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        ureq::serde_json::to_vec(self).map_err(Into::into)
+    }
+}
+
+fn random_nonce() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(NONCE_LEN)
+        .map(char::from)
+        .collect()
+}
+
+pub fn request_token(request: &TokenRequest, key: &PrivateKey) -> Result<Token> {
+    let body = request.to_bytes()?;
+    let (_, signature) = key.sign_with_default_algorithm(&body)?;
+
+    ureq::post(AUTH_URL)
+        .set("Signature", &signature)
+        .send_bytes(&body)
+        .map_err(|_| Error::Token)?
+        .into_json::<TokenResponse>()
+        .map_err(Into::into)
+        .and_then(|response| Token::try_from(response.token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenRequest;
+
+    #[test]
+    fn to_bytes_is_byte_identical_across_calls() {
+        let request = TokenRequest::new("login", "label");
+        assert_eq!(request.to_bytes().unwrap(), request.to_bytes().unwrap());
+    }
+}
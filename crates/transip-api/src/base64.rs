@@ -0,0 +1,17 @@
+use crate::{Error, Result};
+
+pub trait Base64 {
+    fn base64_decode_url_safe(&self) -> Result<Vec<u8>>;
+}
+
+impl Base64 for str {
+    fn base64_decode_url_safe(&self) -> Result<Vec<u8>> {
+        base64::decode_config(self, base64::URL_SAFE_NO_PAD).map_err(Error::from)
+    }
+}
+
+impl Base64 for String {
+    fn base64_decode_url_safe(&self) -> Result<Vec<u8>> {
+        self.as_str().base64_decode_url_safe()
+    }
+}
@@ -0,0 +1,23 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("token error")]
+    Token,
+
+    #[error("key error")]
+    Key,
+
+    #[error("signature error")]
+    Signature,
+
+    #[error("unsupported signature algorithm for this key")]
+    UnsupportedAlgorithm,
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("base64 decode error: {0}")]
+    Base64(#[from] base64::DecodeError),
+}
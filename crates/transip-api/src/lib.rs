@@ -0,0 +1,6 @@
+pub mod authentication;
+mod base64;
+mod error;
+
+pub use error::Error;
+pub type Result<T> = std::result::Result<T, Error>;